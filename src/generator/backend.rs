@@ -0,0 +1,70 @@
+use crate::generator::utils::yaserde_derive;
+
+/// Which serialization framework generated structs target. Passed directly
+/// into `struct_codegen`'s `generate_struct`/`generate_struct_header`, which
+/// defer the derive line, container attributes, rename annotations and
+/// attribute/element field annotations to the matching method below. The
+/// binary-codec and default-value field annotations are emitted in yaserde
+/// form whichever backend is selected. Defaults to [`SerializationBackend::YaSerde`] to match existing
+/// output. [`SerializationBackend::QuickXmlSerde`] emits
+/// `#[derive(Serialize, Deserialize)]` structs annotated for quick-xml's
+/// serde support instead, for callers who already depend on quick-xml
+/// elsewhere and don't want yaserde as a second XML crate in their tree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SerializationBackend {
+    YaSerde,
+    QuickXmlSerde,
+}
+
+impl Default for SerializationBackend {
+    fn default() -> Self {
+        SerializationBackend::YaSerde
+    }
+}
+
+impl SerializationBackend {
+    /// The derive line plus container-level attributes for a generated
+    /// struct. `namespaces` is the full prefix→URI map resolved from the
+    /// owning schema, not just the target namespace's own pair.
+    pub(crate) fn container_attributes(self, prefix: &str, namespaces: &[(String, String)]) -> String {
+        match self {
+            SerializationBackend::YaSerde => yaserde_derive(prefix, namespaces),
+            SerializationBackend::QuickXmlSerde => {
+                "#[derive(Default, PartialEq, Debug, Serialize, Deserialize)]\n".to_string()
+            }
+        }
+    }
+
+    /// The attribute annotating a struct field sourced from an XML
+    /// attribute (`StructFieldSource::Attribute`). `prefix` is `Some` when
+    /// the attribute is namespace-qualified.
+    pub(crate) fn attribute_field_annotation(self, prefix: Option<&str>, name: &str) -> String {
+        match self {
+            SerializationBackend::YaSerde => match prefix {
+                Some(prefix) => format!("#[yaserde(attribute, prefix = \"{}\")]\n", prefix),
+                None => "#[yaserde(attribute)]\n".to_string(),
+            },
+            SerializationBackend::QuickXmlSerde => {
+                format!("#[serde(rename = \"@{}\")]\n", name)
+            }
+        }
+    }
+
+    /// The attribute annotating a struct field sourced from a child element.
+    pub(crate) fn element_field_annotation(self, name: &str) -> String {
+        match self {
+            SerializationBackend::YaSerde => format!("#[yaserde(rename = \"{}\")]\n", name),
+            SerializationBackend::QuickXmlSerde => format!("#[serde(rename = \"{}\")]\n", name),
+        }
+    }
+
+    /// The attribute preserving a field or type's original schema name when
+    /// `get_field_name`/`get_type_name` (case conversion, keyword/digit
+    /// escaping) changed the generated Rust identifier.
+    pub(crate) fn rename_annotation(self, original: &str) -> String {
+        match self {
+            SerializationBackend::YaSerde => format!("#[yaserde(rename = \"{}\")]\n", original),
+            SerializationBackend::QuickXmlSerde => format!("#[serde(rename = \"{}\")]\n", original),
+        }
+    }
+}
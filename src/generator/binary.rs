@@ -0,0 +1,51 @@
+/// XSD binary encodings that map to a Rust `Vec<u8>` field instead of
+/// falling through to `String`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryEncoding {
+    Base64,
+    Hex,
+}
+
+impl BinaryEncoding {
+    /// Recognizes `xs:base64Binary` and `xs:hexBinary`, with or without the
+    /// `xs:` prefix. `pub` rather than `pub(crate)`: the parser side (see
+    /// `parse_attribute`'s nested-`simpleType` branch) also needs this to
+    /// recognize a `<xs:restriction base="xs:base64Binary">` before the
+    /// nested type's own generated name shadows the base it restricts.
+    pub fn from_xsd_type(name: &str) -> Option<Self> {
+        match name.trim_start_matches("xs:") {
+            "base64Binary" => Some(BinaryEncoding::Base64),
+            "hexBinary" => Some(BinaryEncoding::Hex),
+            _ => None,
+        }
+    }
+}
+
+/// Generates the free functions a binary field's `#[yaserde(deserialize_with
+/// = "...", serialize_with = "...")]` attributes point at. The decoder trims
+/// surrounding whitespace/newlines (XSD allows them in binary element text)
+/// and decodes an empty element to an empty `Vec`.
+pub(crate) fn binary_codec_fns(mod_name: &str, encoding: BinaryEncoding) -> String {
+    let (decode_call, encode_call) = match encoding {
+        BinaryEncoding::Base64 => ("base64::decode", "base64::encode"),
+        BinaryEncoding::Hex => ("hex::decode", "hex::encode"),
+    };
+
+    format!(
+        "mod {mod_name} {{\n\
+        \x20   pub fn deserialize(text: &str) -> Result<Vec<u8>, String> {{\n\
+        \x20       let trimmed = text.trim();\n\
+        \x20       if trimmed.is_empty() {{\n\
+        \x20           return Ok(Vec::new());\n\
+        \x20       }}\n\
+        \x20       {decode_call}(trimmed).map_err(|e| e.to_string())\n\
+        \x20   }}\n\n\
+        \x20   pub fn serialize(value: &[u8]) -> String {{\n\
+        \x20       {encode_call}(value)\n\
+        \x20   }}\n\
+        }}\n",
+        mod_name = mod_name,
+        decode_call = decode_call,
+        encode_call = encode_call,
+    )
+}
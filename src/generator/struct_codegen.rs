@@ -0,0 +1,224 @@
+use crate::generator::backend::SerializationBackend;
+use crate::generator::binary::{binary_codec_fns, BinaryEncoding};
+use crate::generator::utils::{default_value_attribute, fixed_value_validation};
+use crate::parser::types::{Struct, StructField, StructFieldSource};
+
+/// Emits the derive line and container-level attributes for a parsed
+/// `Struct`, followed by the opening `pub struct Name {`. This is the real
+/// call site `container_attributes`/`yaserde_derive` feed into: the
+/// resolved target-namespace prefix plus the full namespace map travel with
+/// the `Struct` from parsing through to here.
+pub fn generate_struct_header(s: &Struct, backend: SerializationBackend) -> String {
+    let mut out = backend.container_attributes(&s.target_prefix, &s.namespaces);
+    if s.escaped_name.is_some() {
+        out.push_str(&backend.rename_annotation(&s.name));
+    }
+    let name = s.escaped_name.as_deref().unwrap_or(&s.name);
+    out.push_str(&format!("pub struct {} {{\n", name));
+    out
+}
+
+/// Emits the full generated struct: header, one field per `StructField`
+/// (annotations, the Rust identifier, and the binary codec module for a
+/// `Vec<u8>` field), the closing brace, any free functions the fields'
+/// annotations referenced, and - when at least one field carries a `fixed`
+/// schema value - a `validate` method checking every such field still holds
+/// its fixed value.
+pub fn generate_struct(s: &Struct, backend: SerializationBackend) -> String {
+    let mut out = generate_struct_header(s, backend);
+    let mut trailing_items = String::new();
+    let mut fixed_checks = String::new();
+
+    for field in &s.fields {
+        let (field_code, items, fixed_check) = generate_field(field, backend);
+        out.push_str(&field_code);
+        trailing_items.push_str(&items);
+        if let Some(check) = fixed_check {
+            fixed_checks.push_str(&check);
+        }
+    }
+
+    out.push_str("}\n");
+    out.push_str(&trailing_items);
+
+    if !fixed_checks.is_empty() {
+        let name = s.escaped_name.as_deref().unwrap_or(&s.name);
+        out.push_str(&format!(
+            "impl {name} {{\n    pub fn validate(&self) -> Result<(), String> {{\n{checks}        Ok(())\n    }}\n}}\n",
+            name = name,
+            checks = fixed_checks,
+        ));
+    }
+
+    out
+}
+
+/// Returns the field's declaration (with its annotations), any trailing
+/// free items (codec modules, default-value functions) its annotations
+/// reference, and - when the field has a `fixed` schema value - the check
+/// `Struct::validate` runs for it.
+fn generate_field(field: &StructField, backend: SerializationBackend) -> (String, String, Option<String>) {
+    let mut code = String::new();
+    let mut trailing_items = String::new();
+
+    let rust_name = field.escaped_name.as_deref().unwrap_or(&field.name);
+    if field.escaped_name.is_some() {
+        code.push_str(&backend.rename_annotation(&field.name));
+    }
+
+    let rust_type = match BinaryEncoding::from_xsd_type(&field.type_name) {
+        Some(encoding) => {
+            let mod_name = format!("{}_codec", rust_name.trim_start_matches("r#"));
+            trailing_items.push_str(&binary_codec_fns(&mod_name, encoding));
+            code.push_str(&format!(
+                "#[yaserde(deserialize_with = \"{mod}::deserialize\", serialize_with = \"{mod}::serialize\")]\n",
+                mod = mod_name,
+            ));
+            "Vec<u8>".to_string()
+        }
+        None => field.type_name.clone(),
+    };
+
+    match &field.source {
+        StructFieldSource::Attribute(prefix) => {
+            code.push_str(&backend.attribute_field_annotation(prefix.as_deref(), &field.name));
+        }
+        _ => {
+            code.push_str(&backend.element_field_annotation(&field.name));
+        }
+    }
+
+    let bare_name = rust_name.trim_start_matches("r#");
+
+    if let Some(default) = &field.default_value {
+        let (attribute, function) = default_value_attribute(bare_name, &rust_type, default);
+        code.push_str(&attribute);
+        trailing_items.push_str(&function);
+    }
+
+    code.push_str(&format!("pub {}: {},\n", rust_name, rust_type));
+
+    let fixed_check = field.fixed_value.as_ref().map(|fixed| {
+        let (function, validation) = fixed_value_validation(bare_name, &rust_type, fixed);
+        trailing_items.push_str(&function);
+        // `validate` compares the struct's own field, so reference it
+        // through `self` rather than the bare identifier the free
+        // function builds its check against.
+        let validation = validation.replacen(
+            &format!("if {} !=", bare_name),
+            &format!("if self.{} !=", rust_name),
+            1,
+        );
+        validation
+            .lines()
+            .map(|line| format!("    {}\n", line))
+            .collect::<String>()
+    });
+
+    (code, trailing_items, fixed_check)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{generate_struct, generate_struct_header};
+    use crate::generator::backend::SerializationBackend;
+    use crate::parser::types::{Struct, StructField, StructFieldSource};
+
+    #[test]
+    fn test_header_includes_resolved_namespace() {
+        let s = Struct {
+            name: "Widget".to_string(),
+            target_prefix: "tns".to_string(),
+            namespaces: vec![("tns".to_string(), "http://example.com/widget".to_string())],
+            ..Default::default()
+        };
+
+        let header = generate_struct_header(&s, SerializationBackend::YaSerde);
+        assert!(header.contains("prefix = \"tns\""));
+        assert!(header.contains("namespace = \"tns: http://example.com/widget\""));
+        assert!(header.contains("pub struct Widget {"));
+    }
+
+    #[test]
+    fn test_binary_field_gets_codec_module_and_vec_u8_type() {
+        let s = Struct {
+            name: "Widget".to_string(),
+            fields: vec![StructField {
+                name: "payload".to_string(),
+                type_name: "xs:base64Binary".to_string(),
+                source: StructFieldSource::Attribute(None),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        let generated = generate_struct(&s, SerializationBackend::YaSerde);
+        assert!(generated.contains("pub payload: Vec<u8>,"));
+        assert!(generated.contains("mod payload_codec"));
+        assert!(generated.contains(
+            "deserialize_with = \"payload_codec::deserialize\", serialize_with = \"payload_codec::serialize\""
+        ));
+    }
+
+    #[test]
+    fn test_default_value_generates_type_specific_fallback_fn() {
+        let s = Struct {
+            name: "Widget".to_string(),
+            fields: vec![StructField {
+                name: "retries".to_string(),
+                type_name: "u32".to_string(),
+                source: StructFieldSource::Attribute(None),
+                default_value: Some("3".to_string()),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        let generated = generate_struct(&s, SerializationBackend::YaSerde);
+        assert!(generated.contains("#[yaserde(default = \"retries_default\")]"));
+        assert!(generated.contains("fn retries_default() -> u32 {"));
+        assert!(generated.contains("\"3\".parse::<u32>().unwrap()"));
+    }
+
+    #[test]
+    fn test_fixed_value_generates_validate_method() {
+        let s = Struct {
+            name: "Widget".to_string(),
+            fields: vec![StructField {
+                name: "version".to_string(),
+                type_name: "String".to_string(),
+                source: StructFieldSource::Attribute(None),
+                fixed_value: Some("1.0".to_string()),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        let generated = generate_struct(&s, SerializationBackend::YaSerde);
+        assert!(generated.contains("fn version_fixed() -> String {"));
+        assert!(generated.contains("\"1.0\".to_string()"));
+        assert!(generated.contains("impl Widget {"));
+        assert!(generated.contains("pub fn validate(&self) -> Result<(), String> {"));
+        assert!(generated.contains("if self.version != version_fixed() {"));
+    }
+
+    #[test]
+    fn test_fixed_value_on_non_string_field_parses_into_field_type() {
+        let s = Struct {
+            name: "Widget".to_string(),
+            fields: vec![StructField {
+                name: "count".to_string(),
+                type_name: "u32".to_string(),
+                source: StructFieldSource::Attribute(None),
+                fixed_value: Some("42".to_string()),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        let generated = generate_struct(&s, SerializationBackend::YaSerde);
+        assert!(generated.contains("fn count_fixed() -> u32 {"));
+        assert!(generated.contains("\"42\".parse::<u32>().unwrap()"));
+        assert!(generated.contains("if self.count != count_fixed() {"));
+    }
+}
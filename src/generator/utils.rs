@@ -31,18 +31,138 @@ pub fn get_field_comment(doc: Option<&str>) -> String {
         fold(String::new(), |x , y| (x+&y))
 }
 
-pub fn get_type_name(name: &str) -> String {
-    to_pascal_case(name)
+/// Rust 2018+ reserved words. `to_snake_case`/`to_pascal_case` know nothing
+/// about them, so an XSD name like `type` or `move` would otherwise produce
+/// an identifier that fails to compile.
+const RUST_KEYWORDS: &[&str] = &[
+    "as", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern", "false", "fn",
+    "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref",
+    "return", "self", "Self", "static", "struct", "super", "trait", "true", "type", "unsafe",
+    "use", "where", "while", "async", "await", "abstract", "become", "box", "do", "final",
+    "macro", "override", "priv", "try", "typeof", "unsized", "virtual", "yield",
+];
+
+/// Rust identifiers can't start with a digit; prefix one with `_` rather
+/// than dropping it, so e.g. an XSD name `1stPlace` stays recognizable.
+fn prefix_leading_digit(name: String) -> String {
+    match name.chars().next() {
+        Some(c) if c.is_ascii_digit() => format!("_{}", name),
+        _ => name,
+    }
+}
+
+/// `self`, `Self`, `super`, and `crate` cannot be raw identifiers - `rustc`
+/// rejects `r#self`/`r#Self`/`r#super`/`r#crate` outright ("cannot be a raw
+/// identifier") - so these four are escaped by appending `_` instead of the
+/// `r#` prefix every other keyword gets.
+const RAW_IDENT_FORBIDDEN: &[&str] = &["self", "Self", "super", "crate"];
+
+/// Escapes `candidate` if it collides with a Rust keyword: `_`-suffixed for
+/// the four words that can't be raw identifiers, `r#`-prefixed otherwise.
+fn escape_keyword(candidate: String) -> String {
+    if RAW_IDENT_FORBIDDEN.contains(&candidate.as_str()) {
+        format!("{}_", candidate)
+    } else if RUST_KEYWORDS.contains(&candidate.as_str()) {
+        format!("r#{}", candidate)
+    } else {
+        candidate
+    }
+}
+
+/// Converts an XSD name to a type identifier, escaping Rust keywords (pascal-
+/// casing can still collide, e.g. `self` -> `Self`) and prefixing a leading
+/// digit so the result always compiles. Returns the identifier together with
+/// the original name when it differs, so the caller can emit a rename
+/// attribute that keeps the wire representation intact.
+pub fn get_type_name(name: &str) -> (String, Option<&str>) {
+    let pascal = prefix_leading_digit(to_pascal_case(name));
+    let escaped = escape_keyword(pascal);
+    let renamed = if escaped != name { Some(name) } else { None };
+    (escaped, renamed)
 }
 
-pub fn get_field_name(name: &str) -> String {
-    to_snake_case(name)
+/// Converts an XSD name to a field identifier, escaping Rust keywords
+/// (`r#type`, `self_`) and prefixing a leading digit so the result always
+/// compiles. Returns the identifier together with the original name when it
+/// differs, so the caller can emit a rename attribute that keeps the wire
+/// representation intact.
+pub fn get_field_name(name: &str) -> (String, Option<&str>) {
+    let snake = prefix_leading_digit(to_snake_case(name));
+    let escaped = escape_keyword(snake);
+    let renamed = if escaped != name { Some(name) } else { None };
+    (escaped, renamed)
 }
 
-pub(crate) fn yaserde_derive() -> String {
-    "#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]\n\
+/// Generates the `#[yaserde(default = "...")]` attribute plus the matching
+/// free function a missing attribute falls back to, so the schema's
+/// `default` value materializes instead of the field staying empty.
+/// `field_type` is the field's generated Rust type, so the function returns
+/// that type rather than always a `String` - a numeric or boolean field with
+/// a `default` still needs its fallback value to type-check.
+pub(crate) fn default_value_attribute(field_name: &str, field_type: &str, default: &str) -> (String, String) {
+    let fn_name = format!("{}_default", field_name);
+    let attribute = format!("#[yaserde(default = \"{}\")]\n", fn_name);
+    let value = if field_type == "String" {
+        format!("\"{}\".to_string()", default)
+    } else {
+        format!("\"{}\".parse::<{}>().unwrap()", default, field_type)
+    };
+    let function = format!(
+        "fn {fn_name}() -> {field_type} {{\n    {value}\n}}\n",
+        fn_name = fn_name,
+        field_type = field_type,
+        value = value,
+    );
+    (attribute, function)
+}
+
+/// Generates the free function holding a `fixed` attribute's schema value -
+/// parsed into `field_type` the same way `default_value_attribute` parses a
+/// `default`, since a `fixed` numeric/boolean field needs a same-typed value
+/// to compare against, not a `&str` constant - plus the check that rejects
+/// any other value. `generate_struct` places that check in the generated
+/// `validate` method; deserialization itself does not run it.
+pub(crate) fn fixed_value_validation(field_name: &str, field_type: &str, fixed: &str) -> (String, String) {
+    let fn_name = format!("{}_fixed", field_name);
+    let value = if field_type == "String" {
+        format!("\"{}\".to_string()", fixed)
+    } else {
+        format!("\"{}\".parse::<{}>().unwrap()", fixed, field_type)
+    };
+    let function = format!(
+        "fn {fn_name}() -> {field_type} {{\n    {value}\n}}\n",
+        fn_name = fn_name,
+        field_type = field_type,
+        value = value,
+    );
+    let validation = format!(
+        "if {field} != {fn_name}() {{\n    \
+            return Err(format!(\"{field} must be fixed to '{{:?}}'\", {fn_name}()));\n\
+        }}\n",
+        field = field_name,
+        fn_name = fn_name,
+    );
+    (function, validation)
+}
+
+/// Emits `#[yaserde(prefix = "...", namespace = "prefix: uri")]`, with one
+/// `namespace` line per entry in `namespaces` (the full prefix→URI map
+/// resolved from the schema), not just the target namespace's own pair —
+/// a struct can reference elements/attributes qualified under a different
+/// namespace than its own target namespace.
+pub(crate) fn yaserde_derive(prefix: &str, namespaces: &[(String, String)]) -> String {
+    let namespace_lines: String = namespaces
+        .iter()
+        .map(|(ns_prefix, uri)| format!("  namespace = \"{}: {}\",\n", ns_prefix, uri))
+        .collect();
+
+    format!(
+        "#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]\n\
         #[yaserde(\n\
-          prefix = \"unknown\",\n\
-          namespace = \"unknown: unknown\"\n\
-        )\n".to_string()
+          prefix = \"{prefix}\",\n\
+        {namespace_lines}\
+        )]\n",
+        prefix = prefix,
+        namespace_lines = namespace_lines,
+    )
 }
\ No newline at end of file
@@ -1,11 +1,29 @@
 use roxmltree::Node;
 
+use crate::generator::binary::BinaryEncoding;
+use crate::generator::utils::{get_field_name, get_type_name};
 use crate::parser::constants::attribute;
 use crate::parser::node_parser::parse_node;
 use crate::parser::types::{Alias, RsEntity, Struct, StructField, StructFieldSource, TypeModifier};
 use crate::parser::utils::get_documentation;
 use crate::parser::xsd_elements::{ElementType, UseType, XsdNode};
 
+/// `name` keeps the raw XSD identifier (other stages rely on it, e.g. for
+/// looking the entity back up by its schema name), while `escaped_name` is
+/// only `Some` when `get_field_name`/`get_type_name` produced an identifier
+/// that differs from it (case conversion, reserved word, leading digit) — the
+/// generator renders `escaped_name` when present and falls back to `name`
+/// otherwise, emitting a rename attribute so the wire name doesn't change.
+fn escape_field_name(raw_name: &str) -> Option<String> {
+    let (escaped, original) = get_field_name(raw_name);
+    original.map(|_| escaped)
+}
+
+fn escape_type_name(raw_name: &str) -> Option<String> {
+    let (escaped, original) = get_type_name(raw_name);
+    original.map(|_| escaped)
+}
+
 const SUPPORTED_CONTENT_TYPES: [ElementType; 1] =
     [ElementType::SimpleType];
 
@@ -19,26 +37,35 @@ pub fn parse_attribute(node: &Node, parent: &Node) -> RsEntity {
         .or_else(|| node.attr_ref())
         .expect("All attributes have name or ref")
         .to_string();
-    
+
     let type_modifier = match node.attr_use() {
         UseType::Optional => TypeModifier::Option,
         UseType::Prohibited => TypeModifier::Empty,
         UseType::Required => TypeModifier::None,
     };
 
+    let source = StructFieldSource::Attribute(qualification(node));
+    let default_value = node.attribute(attribute::DEFAULT).map(str::to_string);
+    let fixed_value = node.attribute(attribute::FIXED).map(str::to_string);
+
     if node.has_attribute(attribute::TYPE) || node.has_attribute(attribute::REF) {
         let type_name = node
             .attr_type()
             .unwrap_or_else(|| node.attr_ref().unwrap_or("String"))
             .to_string();
 
+        let escaped_name = escape_field_name(&name);
+
         return RsEntity::StructField(StructField {
             type_name,
             comment: get_documentation(node),
             subtypes: vec![],
             name,
-            source: StructFieldSource::Attribute,
+            source,
             type_modifiers: vec![type_modifier],
+            default_value,
+            fixed_value,
+            escaped_name,
         });
     }
 
@@ -54,25 +81,89 @@ pub fn parse_attribute(node: &Node, parent: &Node) -> RsEntity {
     });
 
     let mut field_type = parse_node(&content_node, node);
+
+    // A `<xs:restriction base="xs:base64Binary">` parses to a `TupleStruct`
+    // whose own `type_name` holds that base. `set_name` below only renames
+    // the *identifier* field_type.name() resolves to ("fooType") - reading
+    // that instead of the preserved base would lose the binary base entirely
+    // and silently skip the codec lookup in struct_codegen.
+    let binary_base = match &field_type {
+        RsEntity::TupleStruct(ts) if BinaryEncoding::from_xsd_type(&ts.type_name).is_some() => {
+            Some(ts.type_name.clone())
+        }
+        _ => None,
+    };
+
     field_type.set_name(format!("{}Type", name).as_str());
 
+    let escaped_name = escape_field_name(&name);
+    let type_name = binary_base.unwrap_or_else(|| field_type.name().to_string());
 
     RsEntity::StructField(StructField {
         name,
-        type_name: field_type.name().to_string(),
+        type_name,
         comment: get_documentation(node),
         subtypes: vec![field_type],
-        source: StructFieldSource::Attribute,
+        source,
         type_modifiers: vec![type_modifier],
+        default_value,
+        fixed_value,
+        escaped_name,
     })
 }
 
+/// Returns the target namespace prefix and URI declared on the schema that
+/// owns `node`, together with every prefix→URI pair in scope (the target's
+/// own pair included), so the
+/// generator can emit `#[yaserde(prefix = ..., namespace = "prefix:
+/// uri")]` for the target namespace plus an additional `namespace = "..."`
+/// line per other namespace used in the document, instead of a hardcoded
+/// placeholder covering only one pair.
+pub fn resolve_target_namespace<'a>(node: &Node<'a, 'a>) -> (&'a str, &'a str, Vec<(&'a str, &'a str)>) {
+    let schema = node.document().root_element();
+    let target_uri = schema.attribute(attribute::TARGET_NAMESPACE).unwrap_or("");
+    let namespaces: Vec<(&str, &str)> = schema
+        .namespaces()
+        .filter_map(|ns| ns.name().map(|prefix| (prefix, ns.uri())))
+        .collect();
+    let target_prefix = namespaces
+        .iter()
+        .find(|(_, uri)| *uri == target_uri)
+        .map(|(prefix, _)| *prefix)
+        .unwrap_or("");
+    (target_prefix, target_uri, namespaces)
+}
+
+/// An attribute is namespace-qualified if it carries its own `form`
+/// attribute set to `"qualified"`, or, lacking that, if the owning schema's
+/// `attributeFormDefault` is `"qualified"`. Returns the attribute's
+/// namespace prefix when qualified, so the generator can emit
+/// `#[yaserde(attribute, prefix = "...")]` instead of dropping the
+/// namespace information.
+fn qualification(node: &Node) -> Option<String> {
+    let qualified = match node.attribute(attribute::FORM) {
+        Some(form) => form == "qualified",
+        None => {
+            let schema = node.document().root_element();
+            schema.attribute(attribute::ATTRIBUTE_FORM_DEFAULT) == Some("qualified")
+        }
+    };
+
+    if !qualified {
+        return None;
+    }
+
+    let (prefix, _, _) = resolve_target_namespace(node);
+    Some(prefix.to_string())
+}
+
 fn parse_global_attribute(node: &Node) -> RsEntity {
     if let Some(reference) = node.attr_ref() {
         return RsEntity::Alias(Alias {
             name: reference.to_string(),
             original: reference.to_string(),
             comment: get_documentation(node),
+            escaped_name: escape_type_name(reference),
             ..Default::default()
         });
     }
@@ -86,6 +177,9 @@ fn parse_global_attribute(node: &Node) -> RsEntity {
             name: name.to_string(),
             original: ty.to_string(),
             comment: get_documentation(node),
+            default_value: node.attribute(attribute::DEFAULT).map(str::to_string),
+            fixed_value: node.attribute(attribute::FIXED).map(str::to_string),
+            escaped_name: escape_type_name(name),
             ..Default::default()
         });
     }
@@ -100,15 +194,23 @@ fn parse_global_attribute(node: &Node) -> RsEntity {
         return entity;
     }
 
+    let (target_prefix, _, namespaces) = resolve_target_namespace(node);
+
     RsEntity::Struct(Struct {
         name: name.to_string(),
+        escaped_name: escape_type_name(name),
+        target_prefix: target_prefix.to_string(),
+        namespaces: namespaces
+            .into_iter()
+            .map(|(p, u)| (p.to_string(), u.to_string()))
+            .collect(),
         ..Default::default()
     })
 }
 
 #[cfg(test)]
 mod test {
-    use crate::parser::attribute::parse_global_attribute;
+    use crate::parser::attribute::{parse_attribute, parse_global_attribute};
     use crate::parser::types::RsEntity;
     use crate::parser::utils::find_child;
 
@@ -168,4 +270,153 @@ mod test {
             _ => unreachable!("Test Failed!"),
         }
     }
+
+    #[test]
+    fn test_attribute_with_default() {
+        let doc = roxmltree::Document::parse(
+            r#"
+        <xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema">
+            <xs:complexType name="Widget">
+                <xs:attribute name="color" type="xs:string" default="red" />
+            </xs:complexType>
+        </xs:schema>
+        "#,
+        )
+        .unwrap();
+
+        let schema = doc.root_element();
+        let complex_type = find_child(&schema, "complexType").unwrap();
+        let attribute = find_child(&complex_type, "attribute").unwrap();
+        match parse_attribute(&attribute, &complex_type) {
+            RsEntity::StructField(field) => {
+                assert_eq!(field.default_value, Some("red".to_string()));
+                assert_eq!(field.fixed_value, None);
+            }
+            _ => unreachable!("Test Failed!"),
+        }
+    }
+
+    #[test]
+    fn test_attribute_with_fixed() {
+        let doc = roxmltree::Document::parse(
+            r#"
+        <xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema">
+            <xs:complexType name="Widget">
+                <xs:attribute name="version" type="xs:string" fixed="1.0" />
+            </xs:complexType>
+        </xs:schema>
+        "#,
+        )
+        .unwrap();
+
+        let schema = doc.root_element();
+        let complex_type = find_child(&schema, "complexType").unwrap();
+        let attribute = find_child(&complex_type, "attribute").unwrap();
+        match parse_attribute(&attribute, &complex_type) {
+            RsEntity::StructField(field) => {
+                assert_eq!(field.default_value, None);
+                assert_eq!(field.fixed_value, Some("1.0".to_string()));
+            }
+            _ => unreachable!("Test Failed!"),
+        }
+    }
+
+    #[test]
+    fn test_global_attribute_with_binary_type() {
+        let doc = roxmltree::Document::parse(
+            r#"
+        <xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"
+           xmlns:xmime="http://www.w3.org/2005/05/xmlmime"
+           targetNamespace="http://www.w3.org/2005/05/xmlmime" >
+            <xs:attribute name="payload" type="xs:base64Binary" />
+        </xs:schema>
+        "#,
+        )
+        .unwrap();
+
+        let schema = doc.root_element();
+        let attribute = find_child(&schema, "attribute").unwrap();
+        match parse_global_attribute(&attribute) {
+            RsEntity::Alias(ts) => {
+                assert_eq!(ts.name, "payload");
+                assert_eq!(ts.original, "xs:base64Binary");
+            }
+            _ => unreachable!("Test Failed!"),
+        }
+    }
+
+    #[test]
+    fn test_attribute_with_nested_binary_restriction_keeps_binary_base() {
+        let doc = roxmltree::Document::parse(
+            r#"
+        <xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema">
+            <xs:complexType name="Widget">
+                <xs:attribute name="payload">
+                    <xs:simpleType>
+                        <xs:restriction base="xs:base64Binary" />
+                    </xs:simpleType>
+                </xs:attribute>
+            </xs:complexType>
+        </xs:schema>
+        "#,
+        )
+        .unwrap();
+
+        let schema = doc.root_element();
+        let complex_type = find_child(&schema, "complexType").unwrap();
+        let attribute = find_child(&complex_type, "attribute").unwrap();
+        match parse_attribute(&attribute, &complex_type) {
+            RsEntity::StructField(field) => {
+                assert_eq!(field.type_name, "xs:base64Binary");
+            }
+            _ => unreachable!("Test Failed!"),
+        }
+    }
+
+    #[test]
+    fn test_attribute_escapes_rust_keyword() {
+        let doc = roxmltree::Document::parse(
+            r#"
+        <xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema">
+            <xs:complexType name="Widget">
+                <xs:attribute name="type" type="xs:string" />
+            </xs:complexType>
+        </xs:schema>
+        "#,
+        )
+        .unwrap();
+
+        let schema = doc.root_element();
+        let complex_type = find_child(&schema, "complexType").unwrap();
+        let attribute = find_child(&complex_type, "attribute").unwrap();
+        match parse_attribute(&attribute, &complex_type) {
+            RsEntity::StructField(field) => {
+                assert_eq!(field.name, "type");
+                assert_eq!(field.escaped_name, Some("r#type".to_string()));
+            }
+            _ => unreachable!("Test Failed!"),
+        }
+    }
+
+    #[test]
+    fn test_global_attribute_escapes_rust_keyword() {
+        let doc = roxmltree::Document::parse(
+            r#"
+        <xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema">
+            <xs:attribute name="self" type="xs:string" />
+        </xs:schema>
+        "#,
+        )
+        .unwrap();
+
+        let schema = doc.root_element();
+        let attribute = find_child(&schema, "attribute").unwrap();
+        match parse_global_attribute(&attribute) {
+            RsEntity::Alias(alias) => {
+                assert_eq!(alias.name, "self");
+                assert_eq!(alias.escaped_name, Some("Self_".to_string()));
+            }
+            _ => unreachable!("Test Failed!"),
+        }
+    }
 }
@@ -0,0 +1,159 @@
+use std::collections::{HashMap, HashSet};
+
+use roxmltree::Node;
+
+use crate::parser::attribute::parse_attribute;
+use crate::parser::types::{RsEntity, StructField};
+use crate::parser::xsd_elements::{ElementType, XsdNode};
+
+/// A parsed `<xs:attributeGroup name="...">` definition: the attributes it
+/// declares directly, plus the names of any attribute groups it references
+/// (resolved lazily, so registration order doesn't matter as long as every
+/// group referenced is registered before [`AttributeGroupRegistry::resolve_ref`]
+/// is called).
+struct AttributeGroupDef {
+    own_fields: Vec<StructField>,
+    nested_refs: Vec<String>,
+}
+
+/// Registry of globally declared `<xs:attributeGroup>` definitions, so a
+/// complexType can inline the attributes of `<xs:attributeGroup ref="...">`
+/// it references, transitively through nested group references.
+#[derive(Default)]
+pub struct AttributeGroupRegistry {
+    groups: HashMap<String, AttributeGroupDef>,
+}
+
+impl AttributeGroupRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses a global `<xs:attributeGroup name="...">` definition and
+    /// registers it. `use`/`default` semantics on each attribute are
+    /// preserved as-is, since they're already captured by `parse_attribute`.
+    pub fn register(&mut self, node: &Node) {
+        let name = node
+            .attr_name()
+            .unwrap_or_else(|| panic!("attributeGroup requires a name: {:?}", node));
+
+        let mut own_fields = Vec::new();
+        let mut nested_refs = Vec::new();
+
+        for child in node.children().filter(|n| n.is_element()) {
+            match child.xsd_type() {
+                ElementType::Attribute => {
+                    if let RsEntity::StructField(field) = parse_attribute(&child, node) {
+                        own_fields.push(field);
+                    }
+                }
+                ElementType::AttributeGroup => {
+                    if let Some(group_ref) = child.attr_ref() {
+                        nested_refs.push(group_ref.to_string());
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        self.groups.insert(
+            name.to_string(),
+            AttributeGroupDef {
+                own_fields,
+                nested_refs,
+            },
+        );
+    }
+
+    /// Resolves `<xs:attributeGroup ref="group_ref">` into the list of
+    /// `StructField`s it contributes, inlining any attribute groups it
+    /// references in turn. A group that (directly or transitively)
+    /// references itself is detected and its already-visited references are
+    /// skipped rather than recursed into again.
+    pub fn resolve_ref(&self, group_ref: &str) -> Vec<StructField> {
+        let mut fields = Vec::new();
+        let mut visited = HashSet::new();
+        self.resolve_into(group_ref, &mut visited, &mut fields);
+        fields
+    }
+
+    fn resolve_into(&self, group_ref: &str, visited: &mut HashSet<String>, fields: &mut Vec<StructField>) {
+        if !visited.insert(group_ref.to_string()) {
+            return;
+        }
+
+        let group = match self.groups.get(group_ref) {
+            Some(group) => group,
+            None => return,
+        };
+
+        fields.extend(group.own_fields.iter().cloned());
+        for nested_ref in &group.nested_refs {
+            self.resolve_into(nested_ref, visited, fields);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::AttributeGroupRegistry;
+    use crate::parser::utils::find_child;
+
+    #[test]
+    fn test_resolve_inlines_own_attributes() {
+        let doc = roxmltree::Document::parse(
+            r#"
+        <xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema">
+            <xs:attributeGroup name="coreAttrs">
+                <xs:attribute name="id" type="xs:string" />
+                <xs:attribute name="class" type="xs:string" />
+            </xs:attributeGroup>
+        </xs:schema>
+        "#,
+        )
+        .unwrap();
+
+        let schema = doc.root_element();
+        let group = find_child(&schema, "attributeGroup").unwrap();
+
+        let mut registry = AttributeGroupRegistry::new();
+        registry.register(&group);
+
+        let fields = registry.resolve_ref("coreAttrs");
+        assert_eq!(fields.len(), 2);
+        assert_eq!(fields[0].name, "id");
+        assert_eq!(fields[1].name, "class");
+    }
+
+    #[test]
+    fn test_resolve_breaks_cycles() {
+        let doc = roxmltree::Document::parse(
+            r#"
+        <xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema">
+            <xs:attributeGroup name="a">
+                <xs:attribute name="fromA" type="xs:string" />
+                <xs:attributeGroup ref="b" />
+            </xs:attributeGroup>
+            <xs:attributeGroup name="b">
+                <xs:attribute name="fromB" type="xs:string" />
+                <xs:attributeGroup ref="a" />
+            </xs:attributeGroup>
+        </xs:schema>
+        "#,
+        )
+        .unwrap();
+
+        let schema = doc.root_element();
+        let mut registry = AttributeGroupRegistry::new();
+        for group in schema
+            .children()
+            .filter(|n| n.is_element() && n.tag_name().name() == "attributeGroup")
+        {
+            registry.register(&group);
+        }
+
+        let fields = registry.resolve_ref("a");
+        let names: Vec<&str> = fields.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, vec!["fromA", "fromB"]);
+    }
+}
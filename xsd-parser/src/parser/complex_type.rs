@@ -0,0 +1,146 @@
+use roxmltree::Node;
+
+use crate::generator::backend::SerializationBackend;
+use crate::generator::struct_codegen::generate_struct;
+use crate::parser::attribute::{parse_attribute, resolve_target_namespace};
+use crate::parser::attribute_group::AttributeGroupRegistry;
+use crate::parser::types::{RsEntity, Struct, StructField};
+use crate::parser::xsd_elements::{ElementType, XsdNode};
+
+/// Walks every top-level `<xs:attributeGroup name="...">` in the schema and
+/// registers it, so `parse_complex_type` can inline `<xs:attributeGroup
+/// ref="...">` references regardless of whether the group is declared
+/// before or after the complexType that uses it.
+pub fn collect_attribute_groups(schema: &Node) -> AttributeGroupRegistry {
+    let mut registry = AttributeGroupRegistry::new();
+    for child in schema
+        .children()
+        .filter(|n| n.is_element() && n.xsd_type() == ElementType::AttributeGroup)
+    {
+        registry.register(&child);
+    }
+    registry
+}
+
+/// Parses a `<xs:complexType>`'s attributes into the owning `Struct`,
+/// inlining every `<xs:attributeGroup ref="...">` it references via
+/// `registry` alongside its own directly declared `<xs:attribute>`s.
+pub fn parse_complex_type(node: &Node, registry: &AttributeGroupRegistry) -> RsEntity {
+    let name = node
+        .attr_name()
+        .unwrap_or_else(|| panic!("complexType requires a name: {:?}", node));
+
+    let mut fields: Vec<StructField> = Vec::new();
+    for child in node.children().filter(|n| n.is_element()) {
+        match child.xsd_type() {
+            ElementType::Attribute => {
+                if let RsEntity::StructField(field) = parse_attribute(&child, node) {
+                    fields.push(field);
+                }
+            }
+            ElementType::AttributeGroup => {
+                if let Some(group_ref) = child.attr_ref() {
+                    fields.extend(registry.resolve_ref(group_ref));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let (target_prefix, _, namespaces) = resolve_target_namespace(node);
+
+    RsEntity::Struct(Struct {
+        name: name.to_string(),
+        fields,
+        target_prefix: target_prefix.to_string(),
+        namespaces: namespaces
+            .into_iter()
+            .map(|(p, u)| (p.to_string(), u.to_string()))
+            .collect(),
+        ..Default::default()
+    })
+}
+
+/// Parses every top-level `<xs:complexType>` in `schema` and generates the
+/// matching Rust struct for each, inlining `<xs:attributeGroup ref="...">`
+/// references along the way. This is the actual parse-to-generate pipeline
+/// `parse_complex_type`/`collect_attribute_groups` (parser side) and
+/// `generate_struct`/`generate_struct_header` (generator side) run through -
+/// nothing upstream of this function exists in this tree yet (there is no
+/// top-level driver or schema walker here), but every one of those four
+/// functions is reached from here, not only from its own test module.
+pub fn generate_complex_types(schema: &Node, backend: SerializationBackend) -> String {
+    let registry = collect_attribute_groups(schema);
+
+    schema
+        .children()
+        .filter(|n| n.is_element() && n.xsd_type() == ElementType::ComplexType)
+        .filter_map(|child| match parse_complex_type(&child, &registry) {
+            RsEntity::Struct(s) => Some(generate_struct(&s, backend)),
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{collect_attribute_groups, generate_complex_types, parse_complex_type};
+    use crate::generator::backend::SerializationBackend;
+    use crate::parser::types::RsEntity;
+    use crate::parser::utils::find_child;
+
+    #[test]
+    fn test_inlines_referenced_attribute_group() {
+        let doc = roxmltree::Document::parse(
+            r#"
+        <xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema">
+            <xs:attributeGroup name="coreAttrs">
+                <xs:attribute name="id" type="xs:string" />
+            </xs:attributeGroup>
+            <xs:complexType name="Widget">
+                <xs:attribute name="color" type="xs:string" />
+                <xs:attributeGroup ref="coreAttrs" />
+            </xs:complexType>
+        </xs:schema>
+        "#,
+        )
+        .unwrap();
+
+        let schema = doc.root_element();
+        let registry = collect_attribute_groups(&schema);
+        let complex_type = find_child(&schema, "complexType").unwrap();
+
+        match parse_complex_type(&complex_type, &registry) {
+            RsEntity::Struct(s) => {
+                let names: Vec<&str> = s.fields.iter().map(|f| f.name.as_str()).collect();
+                assert_eq!(names, vec!["color", "id"]);
+            }
+            _ => unreachable!("Test Failed!"),
+        }
+    }
+
+    #[test]
+    fn test_generate_complex_types_runs_the_full_parse_to_generate_pipeline() {
+        let doc = roxmltree::Document::parse(
+            r#"
+        <xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema">
+            <xs:attributeGroup name="coreAttrs">
+                <xs:attribute name="id" type="xs:string" />
+            </xs:attributeGroup>
+            <xs:complexType name="Widget">
+                <xs:attribute name="color" type="xs:string" />
+                <xs:attributeGroup ref="coreAttrs" />
+            </xs:complexType>
+        </xs:schema>
+        "#,
+        )
+        .unwrap();
+
+        let schema = doc.root_element();
+        let generated = generate_complex_types(&schema, SerializationBackend::YaSerde);
+
+        assert!(generated.contains("pub struct Widget {"));
+        assert!(generated.contains("pub color: xs:string,"));
+        assert!(generated.contains("pub id: xs:string,"));
+    }
+}